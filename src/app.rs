@@ -1,7 +1,13 @@
+use std::collections::VecDeque;
 use std::io;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
 
-use crate::nord::{self, City, Country, Nord, Status};
-use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crate::config::Config as UserConfig;
+use crate::events::{Config, Event, Events};
+use crate::nord::{self, City, Country, Nord, NordList, Settings, Status};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
 use rand::Rng;
 use ratatui::layout::Layout;
 use ratatui::{prelude::*, widgets::*};
@@ -9,6 +15,7 @@ use ratatui::{prelude::*, widgets::*};
 enum Pane {
     Country,
     City,
+    Favorites,
 }
 
 impl Pane {
@@ -20,22 +27,35 @@ impl Pane {
         use Pane::*;
         match *self {
             Country => City,
-            City => Country,
+            City => Favorites,
+            Favorites => Country,
         }
     }
 
     fn prev(&self) -> Self {
         use Pane::*;
         match *self {
-            Country => City,
+            Country => Favorites,
             City => Country,
+            Favorites => City,
         }
     }
 }
 
+#[derive(PartialEq)]
+enum AppTab {
+    Browser,
+    Settings,
+}
+
 pub struct StatefulList<T> {
     pub state: ListState,
     pub items: Vec<T>,
+    /// Indices into `items` that are currently visible, in display order. When
+    /// no filter is active this is simply `0..items.len()`.
+    indices: Vec<usize>,
+    /// The active fuzzy query, if the list is being filtered.
+    filter: Option<String>,
 }
 
 pub struct App {
@@ -45,80 +65,396 @@ pub struct App {
     cities: StatefulList<City>,
     ui: Ui,
     help: bool,
+    tick_rate: Duration,
+    status_rx: Option<mpsc::Receiver<Result<Status, String>>>,
+    down_rates: VecDeque<u64>,
+    up_rates: VecDeque<u64>,
+    prev_transfer: Option<(u64, u64)>,
+    last_sample: Option<Instant>,
+    error: Option<String>,
+    config: UserConfig,
+    favorites: StatefulList<String>,
+    tab: AppTab,
+    settings: Settings,
+    settings_state: ListState,
+    searching: bool,
 }
 
+/// Install a panic hook that restores the terminal — leaves the alternate
+/// screen, disables raw mode, shows the cursor — before delegating to the
+/// previously-installed hook, so a panic never leaves a garbled terminal.
+pub fn install_panic_hook() {
+    let original = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = crossterm::terminal::disable_raw_mode();
+        let _ = crossterm::execute!(
+            io::stdout(),
+            crossterm::terminal::LeaveAlternateScreen,
+            crossterm::cursor::Show
+        );
+        original(info);
+    }));
+}
+
+/// Number of instantaneous rate samples kept for the throughput sparklines.
+const RATE_HISTORY: usize = 60;
+
 impl App {
     pub fn new<'a>(nord: Nord) -> App {
-        return App {
+        // The config rate is the default; a `--tick-rate <ms>` CLI arg wins.
+        let tick_rate = Duration::from_millis(nord.config.tick_rate_ms);
+        let favorites = StatefulList::with_items(nord.config.favorites.clone());
+        let mut app = App {
             status: nord.status,
             pane: Pane::first(),
             countries: StatefulList::with_items(nord.countries),
             cities: StatefulList::with_items(vec![]),
             ui: Ui::default(),
             help: false,
+            tick_rate,
+            status_rx: None,
+            down_rates: VecDeque::new(),
+            up_rates: VecDeque::new(),
+            prev_transfer: None,
+            last_sample: None,
+            error: None,
+            config: nord.config,
+            favorites,
+            tab: AppTab::Browser,
+            settings: Settings::default(),
+            settings_state: ListState::default(),
+            searching: false,
         };
+        if let Some(tick_rate) = tick_rate_from_args() {
+            app.set_tick_rate(tick_rate);
+        }
+        return app;
     }
+
+    pub fn set_tick_rate(&mut self, tick_rate: Duration) {
+        self.tick_rate = tick_rate;
+    }
+}
+
+/// Read a `--tick-rate <ms>` / `--tick-rate=<ms>` override from the process
+/// arguments, falling back to `None` when it is absent or unparseable.
+fn tick_rate_from_args() -> Option<Duration> {
+    let mut args = std::env::args();
+    while let Some(arg) = args.next() {
+        if let Some(value) = arg.strip_prefix("--tick-rate=") {
+            return value.parse::<u64>().ok().map(Duration::from_millis);
+        }
+        if arg == "--tick-rate" || arg == "-t" {
+            return args
+                .next()
+                .and_then(|value| value.parse::<u64>().ok())
+                .map(Duration::from_millis);
+        }
+    }
+    return None;
 }
 
 impl App {
     pub fn run(&mut self, mut terminal: Terminal<impl Backend>) -> io::Result<()> {
         self.set_ui(Ui::init(terminal.size().expect("Terminal error")));
+
+        // Auto-connect to the configured default server on launch, if any.
+        if let Some(server) = self.config.default_server.clone() {
+            let result = nord::connect(&server);
+            self.report(result);
+            self.reload_status();
+        }
+
+        let events = Events::with_config(Config {
+            tick_rate: self.tick_rate,
+        });
         loop {
+            self.collect_status();
             self.draw(&mut terminal)?;
 
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
-                    use KeyCode::*;
-                    match key.code {
-                        Char('q') | Esc => {
-                            if self.help {
-                                self.help = !self.help
-                            } else {
-                                return Ok(());
-                            }
-                        }
-                        Char('l') | Right | Tab => self.next_pane(),
-                        Char('h') | Left => self.prev_pane(),
-                        Char('r') => self.reload_status(),
-                        Char('R') => self.connect_random(),
-                        Char('c') | Enter => self.connect_selected(),
-                        Char('d') => self.disconnect(),
-                        Char('?') => self.help = !self.help,
-                        _ => {}
+            match events.next() {
+                Ok(Event::Tick) => self.request_status(),
+                Ok(Event::Input(key)) => {
+                    if self.handle_key(key) {
+                        return Ok(());
                     }
+                }
+                Err(_) => return Ok(()),
+            }
+        }
+    }
+
+    /// Handle a single key press, returning `true` when the app should quit.
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.kind != KeyEventKind::Press {
+            return false;
+        }
+
+        // Any key dismisses an error popup without triggering other actions.
+        if self.error.is_some() {
+            self.error = None;
+            return false;
+        }
+
+        use KeyCode::*;
+
+        // While typing a search query, every key feeds the filter; the shared
+        // quit/tab/help bindings must not steal characters.
+        if self.searching {
+            self.handle_browser_key(key);
+            return false;
+        }
+
+        // Keys shared by every tab.
+        match key.code {
+            Char('q') | Esc => {
+                if self.help {
+                    self.help = false;
+                    return false;
+                }
+                return true;
+            }
+            Tab | BackTab => {
+                self.next_tab();
+                return false;
+            }
+            Char('?') => {
+                self.help = !self.help;
+                return false;
+            }
+            _ => {}
+        }
+
+        match self.tab {
+            AppTab::Browser => self.handle_browser_key(key),
+            AppTab::Settings => self.handle_settings_key(key),
+        }
+
+        false
+    }
 
-                    match self.pane {
-                        Pane::Country => match key.code {
-                            Char('j') | Down => {
-                                self.countries.next();
-                                self.reload_cities();
-                            }
-                            Char('k') | Up => {
-                                self.countries.previous();
-                                self.reload_cities();
-                            }
-                            Char('g') => {
-                                self.countries.first();
-                                self.reload_cities();
-                            }
-                            Char('G') => self.countries.last(),
-                            _ => {}
-                        },
-                        Pane::City => match key.code {
-                            Char('j') | Down => {
-                                self.cities.next();
-                            }
-                            Char('k') | Up => {
-                                self.cities.previous();
-                            }
-                            Char('g') => self.countries.first(),
-                            Char('G') => self.countries.last(),
-                            _ => {}
-                        },
-                    };
+    fn handle_browser_key(&mut self, key: KeyEvent) {
+        use KeyCode::*;
+
+        if self.searching {
+            match key.code {
+                Char(c) => self.push_query(c),
+                Backspace => self.pop_query(),
+                Enter => {
+                    // Connect to the highlighted match, then drop the filter so
+                    // later navigation isn't silently confined to the subset.
+                    self.connect_selected();
+                    self.clear_search();
                 }
+                Esc => self.clear_search(),
+                _ => {}
+            }
+            return;
+        }
+
+        match key.code {
+            Char('/') => {
+                self.searching = true;
+                self.apply_query("");
             }
+            Char('l') | Right => self.next_pane(),
+            Char('h') | Left => self.prev_pane(),
+            Char('r') => self.reload_status(),
+            Char('R') => self.connect_random(),
+            Char('c') | Enter => self.connect_selected(),
+            Char('d') => self.disconnect(),
+            Char('f') => self.toggle_favorite(),
+            _ => {}
         }
+
+        match self.pane {
+            Pane::Country => match key.code {
+                Char('j') | Down => {
+                    self.countries.next();
+                    self.reload_cities();
+                }
+                Char('k') | Up => {
+                    self.countries.previous();
+                    self.reload_cities();
+                }
+                Char('g') => {
+                    self.countries.first();
+                    self.reload_cities();
+                }
+                Char('G') => self.countries.last(),
+                _ => {}
+            },
+            Pane::City => match key.code {
+                Char('j') | Down => {
+                    self.cities.next();
+                }
+                Char('k') | Up => {
+                    self.cities.previous();
+                }
+                Char('g') => self.cities.first(),
+                Char('G') => self.cities.last(),
+                _ => {}
+            },
+            Pane::Favorites => match key.code {
+                Char('j') | Down => self.favorites.next(),
+                Char('k') | Up => self.favorites.previous(),
+                Char('g') => self.favorites.first(),
+                Char('G') => self.favorites.last(),
+                _ => {}
+            },
+        };
+    }
+
+    fn handle_settings_key(&mut self, key: KeyEvent) {
+        use KeyCode::*;
+        match key.code {
+            Char('j') | Down => self.select_setting(1),
+            Char('k') | Up => self.select_setting(-1),
+            Char('c') | Enter => self.toggle_setting(),
+            _ => {}
+        }
+    }
+
+    /// Switch tabs, reading the current NordVPN options when entering Settings.
+    fn next_tab(&mut self) {
+        self.tab = match self.tab {
+            AppTab::Browser => {
+                self.reload_settings();
+                AppTab::Settings
+            }
+            AppTab::Settings => AppTab::Browser,
+        };
+    }
+
+    /// Append a character to the active pane's search query and re-filter.
+    fn push_query(&mut self, c: char) {
+        let mut query = self.active_query();
+        query.push(c);
+        self.apply_query(&query);
+    }
+
+    /// Remove the last character from the active pane's search query.
+    fn pop_query(&mut self) {
+        let mut query = self.active_query();
+        query.pop();
+        self.apply_query(&query);
+    }
+
+    /// Exit search mode and restore the full list in the active pane.
+    fn clear_search(&mut self) {
+        self.searching = false;
+        match self.pane {
+            Pane::Country => {
+                self.countries.clear_filter();
+                self.reload_cities();
+            }
+            Pane::City => self.cities.clear_filter(),
+            Pane::Favorites => self.favorites.clear_filter(),
+        }
+    }
+
+    fn active_query(&self) -> String {
+        let filter = match self.pane {
+            Pane::Country => &self.countries.filter,
+            Pane::City => &self.cities.filter,
+            Pane::Favorites => &self.favorites.filter,
+        };
+        return filter.clone().unwrap_or_default();
+    }
+
+    fn apply_query(&mut self, query: &str) {
+        match self.pane {
+            Pane::Country => {
+                self.countries.set_filter(query);
+                self.reload_cities();
+            }
+            Pane::City => self.cities.set_filter(query),
+            Pane::Favorites => self.favorites.set_filter(query),
+        }
+    }
+
+    /// Toggle the favorite status of the selected connection and persist it.
+    fn toggle_favorite(&mut self) {
+        let name = match self.pane {
+            Pane::Country => selected_name(&self.countries),
+            Pane::City => selected_name(&self.cities),
+            Pane::Favorites => selected_name(&self.favorites),
+        };
+
+        if let Some(name) = name {
+            self.config.toggle_favorite(&name);
+            if let Err(e) = self.config.save() {
+                self.error = Some(format!("config: {e}"));
+            }
+            self.favorites = StatefulList::with_items(self.config.favorites.clone());
+        }
+    }
+
+    fn reload_settings(&mut self) {
+        match nord::get_settings() {
+            Ok(settings) => self.settings = settings,
+            Err(message) => self.error = Some(message),
+        }
+        if self.settings_state.selected().is_none() {
+            self.settings_state.select(Some(0));
+        }
+    }
+
+    fn select_setting(&mut self, delta: i32) {
+        let len = self.settings_labels().len() as i32;
+        if len == 0 {
+            return;
+        }
+        let current = self.settings_state.selected().unwrap_or(0) as i32;
+        let next = (current + delta).rem_euclid(len);
+        self.settings_state.select(Some(next as usize));
+    }
+
+    /// Flip the selected option via `nordvpn set` and re-read the settings.
+    fn toggle_setting(&mut self) {
+        let result = match self.settings_state.selected().unwrap_or(0) {
+            0 => nord::set("killswitch", on_off(!self.settings.killswitch)),
+            1 => nord::set("autoconnect", on_off(!self.settings.autoconnect)),
+            2 => nord::set("threatprotectionlite", on_off(!self.settings.threat_protection)),
+            3 => self.cycle_technology(),
+            _ => Ok(()),
+        };
+        self.report(result);
+        self.reload_settings();
+    }
+
+    /// Cycle the technology/protocol through NordLynx, OpenVPN UDP, OpenVPN TCP.
+    fn cycle_technology(&mut self) -> Result<(), String> {
+        let tech = self.settings.technology.to_uppercase();
+        let protocol = self.settings.protocol.to_uppercase();
+        if tech.contains("NORDLYNX") {
+            nord::set("technology", "openvpn")?;
+            return nord::set("protocol", "udp");
+        }
+        if protocol.contains("UDP") {
+            return nord::set("protocol", "tcp");
+        }
+        return nord::set("technology", "nordlynx");
+    }
+
+    fn settings_labels(&self) -> Vec<String> {
+        return vec![
+            format!("Kill Switch        {}", on_off(self.settings.killswitch)),
+            format!("Auto-connect       {}", on_off(self.settings.autoconnect)),
+            format!("Threat Protection  {}", on_off(self.settings.threat_protection)),
+            format!("Technology         {}", self.technology_label()),
+        ];
+    }
+
+    fn technology_label(&self) -> String {
+        let tech = self.settings.technology.to_uppercase();
+        if tech.contains("NORDLYNX") {
+            return String::from("NordLynx");
+        }
+        if self.settings.protocol.to_uppercase().contains("TCP") {
+            return String::from("OpenVPN TCP");
+        }
+        return String::from("OpenVPN UDP");
     }
 
     fn set_ui(&mut self, ui: Ui) {
@@ -126,56 +462,139 @@ impl App {
     }
 
     fn reload_status(&mut self) {
-        self.status = nord::get_status();
+        match nord::get_status() {
+            Ok(status) => {
+                self.status = status;
+                self.record_transfer();
+            }
+            Err(message) => self.error = Some(message),
+        }
+    }
+
+    /// Surface a failed `nordvpn` invocation as an on-screen error popup.
+    fn report(&mut self, result: Result<(), String>) {
+        if let Err(message) = result {
+            self.error = Some(message);
+        }
+    }
+
+    /// Derive instantaneous download/upload rates from the latest transfer
+    /// counters and push them into the sparkline ring buffers.
+    fn record_transfer(&mut self) {
+        // Reset the baseline on disconnect so stale deltas don't spike the graph.
+        if self.status.status != "Connected" {
+            self.prev_transfer = None;
+            self.last_sample = None;
+            self.push_rate(0, 0);
+            return;
+        }
+
+        let down = nord::parse_bytes(&self.status.transfer.down);
+        let up = nord::parse_bytes(&self.status.transfer.up);
+        let now = Instant::now();
+
+        if let (Some((prev_down, prev_up)), Some(last)) = (self.prev_transfer, self.last_sample) {
+            // Divide by the real elapsed time, not the assumed tick rate, so
+            // slow refreshes and the synchronous reload paths stay accurate.
+            let millis = (now.duration_since(last).as_millis() as u64).max(1);
+            // `saturating_sub` guards against the counter resetting after a reconnect.
+            let down_rate = down.saturating_sub(prev_down) * 1000 / millis;
+            let up_rate = up.saturating_sub(prev_up) * 1000 / millis;
+            self.push_rate(down_rate, up_rate);
+        }
+        self.prev_transfer = Some((down, up));
+        self.last_sample = Some(now);
+    }
+
+    fn push_rate(&mut self, down: u64, up: u64) {
+        if self.down_rates.len() >= RATE_HISTORY {
+            self.down_rates.pop_front();
+        }
+        if self.up_rates.len() >= RATE_HISTORY {
+            self.up_rates.pop_front();
+        }
+        self.down_rates.push_back(down);
+        self.up_rates.push_back(up);
+    }
+
+    /// Kick off a status refresh on a background thread unless one is already
+    /// in flight, so a slow `nordvpn status` never blocks the event loop.
+    fn request_status(&mut self) {
+        // Back off while an error popup is displayed so a persistent failure
+        // (not logged in, missing binary) doesn't re-pop every tick.
+        if self.error.is_some() {
+            return;
+        }
+        if self.status_rx.is_none() {
+            let (tx, rx) = mpsc::channel();
+            thread::spawn(move || {
+                let _ = tx.send(nord::get_status());
+            });
+            self.status_rx = Some(rx);
+        }
+    }
+
+    /// Adopt the result of an in-flight background refresh if it has arrived.
+    fn collect_status(&mut self) {
+        if let Some(rx) = &self.status_rx {
+            match rx.try_recv() {
+                Ok(Ok(status)) => {
+                    self.status = status;
+                    self.status_rx = None;
+                    self.record_transfer();
+                }
+                Ok(Err(message)) => {
+                    // Latch the first failure; don't overwrite a popup the user
+                    // hasn't dismissed yet.
+                    if self.error.is_none() {
+                        self.error = Some(message);
+                    }
+                    self.status_rx = None;
+                }
+                Err(_) => {}
+            }
+        }
     }
 
     fn reload_cities(&mut self) {
-        let idx = match self.countries.state.selected() {
-            Some(i) => i,
-            None => 0,
+        let cities = match self.countries.selected_item() {
+            Some(country) => country.cities.clone(),
+            None => vec![],
         };
-        let country = self.countries.items.get(idx).expect("");
-
-        self.cities = StatefulList::with_items(country.cities.clone());
+        self.cities = StatefulList::with_items(cities);
     }
 
     fn connect_selected(&mut self) {
-        match self.pane {
-            Pane::Country => nord::connect(
-                &self
-                    .countries
-                    .items
-                    .get(match self.countries.state.selected() {
-                        Some(i) => i,
-                        None => 0,
-                    })
-                    .expect("")
-                    .name,
-            ),
-            Pane::City => nord::connect(
-                &self
-                    .cities
-                    .items
-                    .get(match self.cities.state.selected() {
-                        Some(i) => i,
-                        None => 0,
-                    })
-                    .expect("")
-                    .name,
-            ),
-        }
+        let name = match self.pane {
+            Pane::Country => selected_name(&self.countries),
+            Pane::City => selected_name(&self.cities),
+            Pane::Favorites => selected_name(&self.favorites),
+        };
+        let result = match name {
+            Some(name) => nord::connect(&name),
+            None => Ok(()),
+        };
 
+        self.report(result);
         self.reload_status();
     }
 
     fn connect_random(&mut self) {
+        if self.countries.items.is_empty() {
+            return;
+        }
         let random = rand::thread_rng().gen_range(0..self.countries.items.len());
-        nord::connect(&self.countries.items.get(random).expect("").name);
+        let result = match self.countries.items.get(random) {
+            Some(country) => nord::connect(&country.name),
+            None => return,
+        };
+        self.report(result);
         self.reload_status();
     }
 
     fn disconnect(&mut self) {
-        nord::disconnect();
+        let result = nord::disconnect();
+        self.report(result);
         self.reload_status();
     }
 
@@ -197,13 +616,22 @@ impl Widget for &mut App {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let mut ui: Ui = Ui::init(area);
 
-        if self.help {
+        if let Some(message) = self.error.clone() {
+            self.render_title(ui.get_title(), buf);
+            self.render_error(&message, area, buf);
+        } else if self.help {
             self.render_help(area, buf);
         } else {
-            self.render_title(ui.get_title(), buf);
+            self.render_tabs(ui.get_title(), buf);
             self.render_status(ui.get_header(), buf);
-            self.render_countries(ui.get_country(), buf);
-            self.render_cities(ui.get_city(), buf);
+            match self.tab {
+                AppTab::Browser => {
+                    self.render_countries(ui.get_country(), buf);
+                    self.render_cities(ui.get_city(), buf);
+                    self.render_favorites(ui.get_favorites(), buf);
+                }
+                AppTab::Settings => self.render_settings(ui.get_details(), buf),
+            }
             self.render_footer(ui.get_footer(), buf);
         }
     }
@@ -233,7 +661,11 @@ impl Ui {
                 Constraint::Fill(1),
             ]),
             body: Layout::vertical([Constraint::Max(12), Constraint::Max(25)]),
-            details: Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]),
+            details: Layout::horizontal([
+                Constraint::Percentage(34),
+                Constraint::Percentage(33),
+                Constraint::Percentage(33),
+            ]),
         };
     }
 
@@ -258,7 +690,7 @@ impl Ui {
         let [_title_area, body_area, _footer_area] = self.main_frame.areas(self.area);
         let [_left_filler, body_area, _right_filler] = self.body_frame.areas(body_area);
         let [_header_area, details_area] = self.body.areas(body_area);
-        let [country_column, _city_column] = self.details.areas(details_area);
+        let [country_column, _city_column, _favorites_column] = self.details.areas(details_area);
         return country_column;
     }
 
@@ -266,9 +698,24 @@ impl Ui {
         let [_title_area, body_area, _footer_area] = self.main_frame.areas(self.area);
         let [_left_filler, body_area, _right_filler] = self.body_frame.areas(body_area);
         let [_header_area, details_area] = self.body.areas(body_area);
-        let [_country_column, city_column] = self.details.areas(details_area);
+        let [_country_column, city_column, _favorites_column] = self.details.areas(details_area);
         return city_column;
     }
+
+    fn get_favorites(&mut self) -> Rect {
+        let [_title_area, body_area, _footer_area] = self.main_frame.areas(self.area);
+        let [_left_filler, body_area, _right_filler] = self.body_frame.areas(body_area);
+        let [_header_area, details_area] = self.body.areas(body_area);
+        let [_country_column, _city_column, favorites_column] = self.details.areas(details_area);
+        return favorites_column;
+    }
+
+    fn get_details(&mut self) -> Rect {
+        let [_title_area, body_area, _footer_area] = self.main_frame.areas(self.area);
+        let [_left_filler, body_area, _right_filler] = self.body_frame.areas(body_area);
+        let [_header_area, details_area] = self.body.areas(body_area);
+        return details_area;
+    }
 }
 
 impl App {
@@ -277,6 +724,7 @@ impl App {
     Navigation:
         ↓/↑ - Select list item
         ←/→ - Move between panels
+        Tab - Switch between Browser/Settings
         g   - Move to the top of current panel
         G   - Move to the bottom of current panel
 
@@ -285,16 +733,72 @@ impl App {
         d   - Disconnect
         r   - Refresh
         R   - Connect to random option
+        f   - Toggle favorite
+        /   - Filter current list (Esc clears)
 
         ?   - Help
         q   - Quit
             ";
         let block = Block::default().title("Help").borders(Borders::ALL);
         let para = Paragraph::new(help_text).block(block).bg(Color::default());
-        let area = popup(55, 18, area);
+        let area = popup(55, 22, area);
         para.render(area, buf);
     }
 
+    fn render_error(&self, message: &str, area: Rect, buf: &mut Buffer) {
+        let block = Block::default()
+            .title("Error")
+            .borders(Borders::ALL)
+            .fg(Color::Red);
+        let para = Paragraph::new(format!("\n{message}\n\nPress any key to dismiss"))
+            .block(block)
+            .alignment(Alignment::Center)
+            .bg(Color::default());
+        let area = popup(60, 8, area);
+        para.render(area, buf);
+    }
+
+    fn render_tabs(&self, area: Rect, buf: &mut Buffer) {
+        let selected = match self.tab {
+            AppTab::Browser => 0,
+            AppTab::Settings => 1,
+        };
+        Tabs::new(vec!["Browser", "Settings"])
+            .select(selected)
+            .highlight_style(
+                Style::default()
+                    .fg(Color::LightCyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .render(area, buf);
+    }
+
+    fn render_settings(&mut self, area: Rect, buf: &mut Buffer) {
+        let block = Block::default()
+            .borders(Borders::all())
+            .title("Settings")
+            .title_alignment(Alignment::Center);
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        let items: Vec<ListItem> = self
+            .settings_labels()
+            .into_iter()
+            .map(ListItem::new)
+            .collect();
+
+        let items = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .add_modifier(Modifier::REVERSED),
+            )
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(items, inner_area, buf, &mut self.settings_state);
+    }
+
     fn render_title(&self, area: Rect, buf: &mut Buffer) {
         Paragraph::new("Ratatui List Example")
             .bold()
@@ -346,11 +850,20 @@ impl App {
         Paragraph::new(String::from(&self.status.ip))
             .block(Self::get_block("IP"))
             .render(right_col[0], buf);
-        Paragraph::new(String::from(&self.status.transfer.down))
-            .block(Self::get_block("Download"))
+        let down_rate = *self.down_rates.back().unwrap_or(&0);
+        let up_rate = *self.up_rates.back().unwrap_or(&0);
+        let down_data: Vec<u64> = self.down_rates.iter().copied().collect();
+        let up_data: Vec<u64> = self.up_rates.iter().copied().collect();
+
+        Sparkline::default()
+            .block(Self::get_block(&format!("Download  {}", human_rate(down_rate))))
+            .data(&down_data)
+            .style(Style::default().fg(Color::Green))
             .render(right_col[1], buf);
-        Paragraph::new(String::from(&self.status.transfer.up))
-            .block(Self::get_block("Upload"))
+        Sparkline::default()
+            .block(Self::get_block(&format!("Upload  {}", human_rate(up_rate))))
+            .data(&up_data)
+            .style(Style::default().fg(Color::Cyan))
             .render(right_col[2], buf);
     }
 
@@ -369,7 +882,7 @@ impl App {
 
         let outer_block = Block::default()
             .borders(Borders::NONE)
-            .title("Countries")
+            .title(list_title("Countries", &self.countries.filter))
             .style(Style::default().fg(fg))
             .title_alignment(Alignment::Center);
         let inner_block = Block::default()
@@ -383,10 +896,10 @@ impl App {
 
         let items: Vec<ListItem> = self
             .countries
-            .items
+            .indices
             .iter()
-            .enumerate()
-            .map(|(_i, country)| country_to_list_item(country))
+            .filter_map(|&i| self.countries.items.get(i))
+            .map(|country| name_to_list_item(&country.name, self.config.is_favorite(&country.name)))
             .collect();
 
         let items = List::new(items)
@@ -410,7 +923,7 @@ impl App {
 
         let outer_block = Block::default()
             .borders(Borders::NONE)
-            .title("Cities")
+            .title(list_title("Cities", &self.cities.filter))
             .style(Style::default().fg(fg))
             .title_alignment(Alignment::Center);
         let inner_block = Block::default().borders(Borders::all());
@@ -421,10 +934,10 @@ impl App {
         outer_block.render(outer_area, buf);
         let items: Vec<ListItem> = self
             .cities
-            .items
+            .indices
             .iter()
-            .enumerate()
-            .map(|(_i, city)| city_to_list_item(city))
+            .filter_map(|&i| self.cities.items.get(i))
+            .map(|city| name_to_list_item(&city.name, self.config.is_favorite(&city.name)))
             .collect();
 
         let items = List::new(items)
@@ -439,29 +952,151 @@ impl App {
 
         StatefulWidget::render(items, inner_area, buf, &mut self.cities.state);
     }
+
+    fn render_favorites(&mut self, area: Rect, buf: &mut Buffer) {
+        let fg = match self.pane {
+            Pane::Favorites => Color::LightCyan,
+            _ => Color::default(),
+        };
+
+        let outer_block = Block::default()
+            .borders(Borders::NONE)
+            .title(list_title("Favorites", &self.favorites.filter))
+            .style(Style::default().fg(fg))
+            .title_alignment(Alignment::Center);
+        let inner_block = Block::default().borders(Borders::all());
+
+        let outer_area = area;
+        let inner_area = outer_block.inner(outer_area);
+
+        outer_block.render(outer_area, buf);
+        let items: Vec<ListItem> = self
+            .favorites
+            .indices
+            .iter()
+            .filter_map(|&i| self.favorites.items.get(i))
+            .map(|name| ListItem::new(format!("★ {name}")))
+            .collect();
+
+        let items = List::new(items)
+            .block(inner_block)
+            .highlight_style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .add_modifier(Modifier::REVERSED),
+            )
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(items, inner_area, buf, &mut self.favorites.state);
+    }
 }
 
-fn city_to_list_item(city: &City) -> ListItem<'_> {
-    return ListItem::new(String::from(&city.name));
+fn list_title(base: &str, filter: &Option<String>) -> String {
+    match filter {
+        Some(query) => format!("{base} /{query}"),
+        None => String::from(base),
+    }
 }
 
-fn country_to_list_item(country: &Country) -> ListItem<'_> {
-    return ListItem::new(String::from(&country.name));
+fn selected_name<T: NordList>(list: &StatefulList<T>) -> Option<String> {
+    return list.selected_item().map(|it| it.name());
+}
+
+/// Subsequence fuzzy match: every character of `query` must appear in order
+/// within `text`. Returns a score (higher is better) that rewards longer
+/// contiguous runs and penalises a later first match, or `None` when the
+/// query is not a subsequence of the text.
+fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let query = query.to_lowercase();
+    let text = text.to_lowercase();
+    let mut chars = query.chars().peekable();
+    let mut score = 0;
+    let mut run = 0;
+    let mut first_match: Option<i32> = None;
+
+    for (i, ch) in text.chars().enumerate() {
+        match chars.peek() {
+            Some(&expected) if expected == ch => {
+                chars.next();
+                run += 1;
+                score += run;
+                if first_match.is_none() {
+                    first_match = Some(i as i32);
+                }
+            }
+            _ => run = 0,
+        }
+    }
+
+    if chars.peek().is_some() {
+        return None;
+    }
+
+    score -= first_match.unwrap_or(0);
+    return Some(score);
+}
+
+fn on_off(value: bool) -> &'static str {
+    if value {
+        return "on";
+    }
+    return "off";
+}
+
+fn human_rate(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B/s", "KiB/s", "MiB/s", "GiB/s", "TiB/s"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    return format!("{:.2} {}", value, UNITS[unit]);
+}
+
+fn name_to_list_item(name: &str, favorite: bool) -> ListItem<'static> {
+    if favorite {
+        return ListItem::new(format!("★ {name}"));
+    }
+    return ListItem::new(format!("  {name}"));
 }
 
 impl<T> StatefulList<T> {
     pub fn with_items(items: Vec<T>) -> StatefulList<T> {
+        let indices = (0..items.len()).collect();
         StatefulList {
             state: ListState::default(),
             items,
+            indices,
+            filter: None,
         }
     }
 
+    /// Item currently highlighted in the visible (possibly filtered) list.
+    pub fn selected_item(&self) -> Option<&T> {
+        let pos = self.state.selected()?;
+        let idx = *self.indices.get(pos)?;
+        return self.items.get(idx);
+    }
+
+    /// Drop any active filter and restore the full list.
+    pub fn clear_filter(&mut self) {
+        self.indices = (0..self.items.len()).collect();
+        self.filter = None;
+        self.state
+            .select(if self.items.is_empty() { None } else { Some(0) });
+    }
+
     pub fn next(&mut self) {
-        if self.items.len() > 0 {
+        if self.indices.len() > 0 {
             let i = match self.state.selected() {
                 Some(i) => {
-                    if i >= self.items.len() - 1 {
+                    if i >= self.indices.len() - 1 {
                         0
                     } else {
                         i + 1
@@ -474,11 +1109,11 @@ impl<T> StatefulList<T> {
     }
 
     pub fn previous(&mut self) {
-        if self.items.len() > 0 {
+        if self.indices.len() > 0 {
             let i = match self.state.selected() {
                 Some(i) => {
                     if i == 0 {
-                        self.items.len() - 1
+                        self.indices.len() - 1
                     } else {
                         i - 1
                     }
@@ -494,7 +1129,28 @@ impl<T> StatefulList<T> {
     }
 
     pub fn last(&mut self) {
-        self.state.select(Some(self.items.len() - 1));
+        if self.indices.len() > 0 {
+            self.state.select(Some(self.indices.len() - 1));
+        }
+    }
+}
+
+impl<T: NordList> StatefulList<T> {
+    /// Narrow the visible items to those fuzzy-matching `query`, ranked best
+    /// first, and move the selection to the top match.
+    pub fn set_filter(&mut self, query: &str) {
+        let mut scored: Vec<(i32, usize)> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, it)| fuzzy_score(query, &it.name()).map(|score| (score, i)))
+            .collect();
+        // Highest score first; ties keep the original order.
+        scored.sort_by(|a, b| b.0.cmp(&a.0));
+        self.indices = scored.into_iter().map(|(_, i)| i).collect();
+        self.filter = Some(query.to_string());
+        self.state
+            .select(if self.indices.is_empty() { None } else { Some(0) });
     }
 }
 
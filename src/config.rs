@@ -0,0 +1,80 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// Persistent user preferences stored as JSON under the XDG config dir,
+/// loaded on launch and rewritten whenever a favorite is toggled.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Config {
+    /// Favorite connection names (countries or cities) in insertion order.
+    #[serde(default)]
+    pub favorites: Vec<String>,
+    /// Server to auto-connect to on launch, if any.
+    #[serde(default)]
+    pub default_server: Option<String>,
+    /// Status refresh / tick rate in milliseconds.
+    #[serde(default = "default_tick_rate")]
+    pub tick_rate_ms: u64,
+}
+
+fn default_tick_rate() -> u64 {
+    250
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            favorites: vec![],
+            default_server: None,
+            tick_rate_ms: default_tick_rate(),
+        }
+    }
+}
+
+impl Config {
+    /// Load the config from disk, falling back to defaults when the file is
+    /// missing or cannot be parsed.
+    pub fn load() -> Config {
+        match fs::read_to_string(config_path()) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+            Err(_) => Config::default(),
+        }
+    }
+
+    /// Persist the config to disk, creating the config directory if needed.
+    pub fn save(&self) -> io::Result<()> {
+        let path = config_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let contents = serde_json::to_string_pretty(self).unwrap_or_default();
+        return fs::write(path, contents);
+    }
+
+    pub fn is_favorite(&self, name: &str) -> bool {
+        return self.favorites.iter().any(|it| it == name);
+    }
+
+    /// Add or remove `name` from the favorites list.
+    pub fn toggle_favorite(&mut self, name: &str) {
+        match self.favorites.iter().position(|it| it == name) {
+            Some(idx) => {
+                self.favorites.remove(idx);
+            }
+            None => self.favorites.push(name.to_string()),
+        }
+    }
+}
+
+fn config_path() -> PathBuf {
+    let base = match std::env::var("XDG_CONFIG_HOME") {
+        Ok(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => {
+            let home = std::env::var("HOME").unwrap_or_else(|_| String::from("."));
+            PathBuf::from(home).join(".config")
+        }
+    };
+    return base.join("nordvpn-tui").join("config.json");
+}
@@ -0,0 +1,72 @@
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event as CrosstermEvent, KeyEvent};
+
+/// An application event: either a key press forwarded from the input thread
+/// or a timer tick used to drive periodic redraws and status refreshes.
+pub enum Event {
+    Input(KeyEvent),
+    Tick,
+}
+
+/// Configuration for the [`Events`] handler.
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    pub tick_rate: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            tick_rate: Duration::from_millis(250),
+        }
+    }
+}
+
+/// Event handler modelled on the classic tui-rs `Events`/`Config` pattern: a
+/// dedicated thread polls crossterm for key presses and forwards them over an
+/// `mpsc` channel, while emitting a [`Event::Tick`] every `tick_rate`.
+pub struct Events {
+    rx: mpsc::Receiver<Event>,
+    _tx: mpsc::Sender<Event>,
+}
+
+impl Events {
+    pub fn with_config(config: Config) -> Events {
+        let (tx, rx) = mpsc::channel();
+        let event_tx = tx.clone();
+        thread::spawn(move || {
+            let mut last_tick = Instant::now();
+            loop {
+                // Never block past the next tick so the timer stays accurate.
+                let timeout = config
+                    .tick_rate
+                    .checked_sub(last_tick.elapsed())
+                    .unwrap_or_else(|| Duration::from_secs(0));
+
+                if event::poll(timeout).expect("event poll failed") {
+                    if let CrosstermEvent::Key(key) = event::read().expect("event read failed") {
+                        if event_tx.send(Event::Input(key)).is_err() {
+                            return;
+                        }
+                    }
+                }
+
+                if last_tick.elapsed() >= config.tick_rate {
+                    if event_tx.send(Event::Tick).is_err() {
+                        return;
+                    }
+                    last_tick = Instant::now();
+                }
+            }
+        });
+
+        Events { rx, _tx: tx }
+    }
+
+    pub fn next(&self) -> Result<Event, mpsc::RecvError> {
+        self.rx.recv()
+    }
+}
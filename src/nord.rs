@@ -1,9 +1,12 @@
 use std::process::{Command, Output};
 
+use crate::config::Config;
+
 #[derive(Debug)]
 pub struct Nord {
     pub status: Status,
     pub countries: Vec<Country>,
+    pub config: Config,
 }
 
 impl Nord {
@@ -16,7 +19,7 @@ impl Nord {
     }
 
     pub fn refresh_status(&mut self) {
-        self.status = get_status();
+        self.status = get_status().unwrap_or_default();
     }
 }
 
@@ -30,12 +33,37 @@ pub struct Status {
     pub uptime: String,
 }
 
+impl Default for Status {
+    fn default() -> Status {
+        Status {
+            status: String::from("Disconnected"),
+            ip: String::new(),
+            country: String::new(),
+            city: String::new(),
+            transfer: Transfer {
+                down: String::new(),
+                up: String::new(),
+            },
+            uptime: String::new(),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Transfer {
     pub down: String,
     pub up: String,
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct Settings {
+    pub technology: String,
+    pub protocol: String,
+    pub killswitch: bool,
+    pub autoconnect: bool,
+    pub threat_protection: bool,
+}
+
 #[derive(Debug)]
 pub struct Country {
     pub name: String,
@@ -63,18 +91,49 @@ impl NordList for City {
     }
 }
 
+impl NordList for String {
+    fn name(&self) -> String {
+        self.clone()
+    }
+}
+
 pub fn init() -> Nord {
     Nord {
-        status: get_status(),
+        status: get_status().unwrap_or_default(),
         countries: get_countries(),
+        config: Config::load(),
     }
 }
 
-pub fn get_countries() -> Vec<Country> {
+/// Run the `nordvpn` CLI with the given arguments, turning a missing binary or
+/// a failing invocation (non-zero exit, "not logged in") into a human-readable
+/// error string instead of a panic.
+fn run(args: &[&str]) -> Result<Output, String> {
     let output = Command::new("nordvpn")
-        .arg("countries")
+        .args(args)
         .output()
-        .expect("Err...");
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => String::from("nordvpn: command not found"),
+            _ => format!("nordvpn: {e}"),
+        })?;
+
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    if !output.status.success() || stderr.to_lowercase().contains("not logged in") {
+        let message = clean_string(stderr.trim());
+        if message.is_empty() {
+            return Err(String::from("nordvpn: command failed"));
+        }
+        return Err(message);
+    }
+
+    return Ok(output);
+}
+
+pub fn get_countries() -> Vec<Country> {
+    let output = match run(&["countries"]) {
+        Ok(output) => output,
+        Err(_) => return vec![],
+    };
 
     return str_to_vec(parse_output(output), ", ".to_string())
         .iter()
@@ -86,11 +145,10 @@ pub fn get_countries() -> Vec<Country> {
 }
 
 fn get_cities(country: &str) -> Vec<City> {
-    let output = Command::new("nordvpn")
-        .arg("cities")
-        .arg(country)
-        .output()
-        .expect("Err...");
+    let output = match run(&["cities", country]) {
+        Ok(output) => output,
+        Err(_) => return vec![],
+    };
 
     return str_to_vec(parse_output(output), ", ".to_string())
         .iter()
@@ -100,14 +158,11 @@ fn get_cities(country: &str) -> Vec<City> {
         .collect();
 }
 
-pub fn get_status() -> Status {
-    let output = Command::new("nordvpn")
-        .arg("status")
-        .output()
-        .expect("Err...");
+pub fn get_status() -> Result<Status, String> {
+    let output = run(&["status"])?;
 
     let result = parse_output(output);
-    return Status {
+    return Ok(Status {
         status: extract_string(&result, "Status: "),
         ip: extract_string(&result, "IP: "),
         country: extract_string(&result, "Country: "),
@@ -117,7 +172,33 @@ pub fn get_status() -> Status {
             .replace(" hours ", ":")
             .replace(" minutes ", ":")
             .replace(" seconds", ""),
-    };
+    });
+}
+
+/// Read the current NordVPN options via `nordvpn settings`, parsed the same
+/// way [`get_status`] parses the `status` output.
+pub fn get_settings() -> Result<Settings, String> {
+    let output = run(&["settings"])?;
+
+    let result = parse_output(output);
+    return Ok(Settings {
+        technology: extract_string(&result, "Technology: "),
+        protocol: extract_string(&result, "Protocol: "),
+        killswitch: parse_bool(&extract_string(&result, "Kill Switch: ")),
+        autoconnect: parse_bool(&extract_string(&result, "Auto-connect: ")),
+        threat_protection: parse_bool(&extract_string(&result, "Threat Protection Lite: ")),
+    });
+}
+
+/// Set a single NordVPN option, e.g. `set("killswitch", "on")`.
+pub(crate) fn set(option: &str, value: &str) -> Result<(), String> {
+    run(&["set", option, value])?;
+    return Ok(());
+}
+
+fn parse_bool(value: &str) -> bool {
+    let value = value.to_lowercase();
+    return value.contains("enabled") || value == "on" || value == "true";
 }
 
 fn extract_transfer(result: String) -> Transfer {
@@ -127,6 +208,29 @@ fn extract_transfer(result: String) -> Transfer {
     }
 }
 
+/// Convert a transfer string such as "1.23 MiB received" or "4.56 KiB sent"
+/// into an absolute byte count, mapping the KiB/MiB/GiB suffix to its
+/// multiplier. Unrecognised input yields `0`.
+pub fn parse_bytes(input: &str) -> u64 {
+    let mut value = 0.0f64;
+    let mut multiplier = 1.0f64;
+    for token in input.split_whitespace() {
+        match token {
+            "B" => multiplier = 1.0,
+            "KiB" => multiplier = 1024.0,
+            "MiB" => multiplier = 1024.0 * 1024.0,
+            "GiB" => multiplier = 1024.0 * 1024.0 * 1024.0,
+            "TiB" => multiplier = 1024.0 * 1024.0 * 1024.0 * 1024.0,
+            _ => {
+                if let Ok(parsed) = token.parse::<f64>() {
+                    value = parsed;
+                }
+            }
+        }
+    }
+    return (value * multiplier) as u64;
+}
+
 fn extract_string(source: &str, arg: &str) -> String {
     let result = source
         .lines()
@@ -160,14 +264,12 @@ fn clean_string(input: &str) -> String {
     return input.replace("\r-\r  \r\r-\r  \r", "").replace("\n", "");
 }
 
-pub(crate) fn connect(val: &str) {
-    Command::new("nordvpn")
-        .arg("c")
-        .arg(val)
-        .output()
-        .expect("Err...");
+pub(crate) fn connect(val: &str) -> Result<(), String> {
+    run(&["c", val])?;
+    return Ok(());
 }
 
-pub(crate) fn disconnect() {
-    Command::new("nordvpn").arg("d").output().expect("Err...");
+pub(crate) fn disconnect() -> Result<(), String> {
+    run(&["d"])?;
+    return Ok(());
 }